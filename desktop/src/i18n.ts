@@ -63,6 +63,10 @@ interface Translations {
   enabled: string;
   language: string;
   languageDesc: string;
+  accountsBackup: string;
+  accountsBackupDesc: string;
+  exportAccounts: string;
+  importAccounts: string;
   
   // Notifications
   highUsageAlert: string;
@@ -87,10 +91,15 @@ interface Translations {
   confirmDelete: string;
   confirmDeleteDesc: string;
   confirm: string;
+  capExceeded: string;
+  capExceededDesc: string;
+  activateAnyway: string;
   accountDeleted: string;
   accountRenamed: string;
   accountAdded: string;
   settingsSaved: string;
+  accountsExported: string;
+  accountsImported: string;
   loading: string;
   
   // Usage labels
@@ -139,6 +148,10 @@ const translations: Record<Locale, Translations> = {
     enabled: 'Enabled',
     language: 'Language',
     languageDesc: 'Interface language',
+    accountsBackup: 'Accounts Backup',
+    accountsBackupDesc: 'Move accounts to another machine',
+    exportAccounts: 'Export accounts',
+    importAccounts: 'Import accounts',
     highUsageAlert: '⚠️ High Usage Alert',
     fiveHourUsage: '5-hour window at',
     weeklyUsage: 'Weekly window at',
@@ -153,10 +166,15 @@ const translations: Record<Locale, Translations> = {
     confirmDelete: 'Delete Account?',
     confirmDeleteDesc: 'This action cannot be undone.',
     confirm: 'Delete',
+    capExceeded: 'Usage cap reached',
+    capExceededDesc: 'This provider is over the usage cap you set. Switch to this account anyway?',
+    activateAnyway: 'Switch anyway',
     accountDeleted: 'Account deleted',
     accountRenamed: 'Account renamed',
     accountAdded: 'Account added',
     settingsSaved: 'Settings saved',
+    accountsExported: 'Accounts exported',
+    accountsImported: 'Accounts imported',
     loading: 'Loading...',
     remaining: 'remaining',
     used: 'used',
@@ -201,6 +219,10 @@ const translations: Record<Locale, Translations> = {
     enabled: 'Включено',
     language: 'Язык',
     languageDesc: 'Язык интерфейса',
+    accountsBackup: 'Резервная копия',
+    accountsBackupDesc: 'Перенос аккаунтов на другой компьютер',
+    exportAccounts: 'Экспорт аккаунтов',
+    importAccounts: 'Импорт аккаунтов',
     highUsageAlert: '⚠️ Высокое использование',
     fiveHourUsage: '5-часовое окно:',
     weeklyUsage: 'Недельное окно:',
@@ -215,10 +237,15 @@ const translations: Record<Locale, Translations> = {
     confirmDelete: 'Удалить аккаунт?',
     confirmDeleteDesc: 'Это действие нельзя отменить.',
     confirm: 'Удалить',
+    capExceeded: 'Достигнут лимит использования',
+    capExceededDesc: 'Этот провайдер превысил заданный вами лимит. Всё равно переключиться на этот аккаунт?',
+    activateAnyway: 'Переключить',
     accountDeleted: 'Аккаунт удалён',
     accountRenamed: 'Аккаунт переименован',
     accountAdded: 'Аккаунт добавлен',
     settingsSaved: 'Настройки сохранены',
+    accountsExported: 'Аккаунты экспортированы',
+    accountsImported: 'Аккаунты импортированы',
     loading: 'Загрузка...',
     remaining: 'осталось',
     used: 'использовано',
@@ -1,6 +1,8 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { getVersion } from "@tauri-apps/api/app";
 import { isPermissionGranted, requestPermission, sendNotification } from "@tauri-apps/plugin-notification";
+import { open, save } from "@tauri-apps/plugin-dialog";
 import { t, getLocale, setLocale, getAvailableLocales, Locale } from "./i18n";
 import "./styles.css";
 
@@ -31,6 +33,8 @@ const icons = {
   users: `<svg width="18" height="18" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M16 21v-2a4 4 0 0 0-4-4H6a4 4 0 0 0-4 4v2"/><circle cx="9" cy="7" r="4"/><path d="M22 21v-2a4 4 0 0 0-3-3.87"/><path d="M16 3.13a4 4 0 0 1 0 7.75"/></svg>`,
   plus: `<svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2.5" stroke-linecap="round" stroke-linejoin="round"><path d="M12 5v14"/><path d="M5 12h14"/></svg>`,
   trash: `<svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M3 6h18"/><path d="M19 6v14c0 1-1 2-2 2H7c-1 0-2-1-2-2V6"/><path d="M8 6V4c0-1 1-2 2-2h4c1 0 2 1 2 2v2"/></svg>`,
+  download: `<svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"/><path d="m7 10 5 5 5-5"/><path d="M12 15V3"/></svg>`,
+  upload: `<svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"/><path d="m17 8-5-5-5 5"/><path d="M12 3v12"/></svg>`,
   chevronDown: `<svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m6 9 6 6 6-6"/></svg>`,
   // Google/Antigravity sparkle icon
   sparkles: `<svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="m12 3-1.912 5.813a2 2 0 0 1-1.275 1.275L3 12l5.813 1.912a2 2 0 0 1 1.275 1.275L12 21l1.912-5.813a2 2 0 0 1 1.275-1.275L21 12l-5.813-1.912a2 2 0 0 1-1.275-1.275L12 3Z"/><path d="M5 3v4"/><path d="M3 5h4"/><path d="M19 17v4"/><path d="M17 19h4"/></svg>`,
@@ -160,6 +164,10 @@ function setButtonLoading(btnId: string, loading: boolean, loadingText?: string)
 }
 
 // Settings
+function isTauriApp(): boolean {
+  return typeof window !== 'undefined' && '__TAURI__' in window;
+}
+
 function loadSettings(): void {
   try {
     const saved = localStorage.getItem(SETTINGS_KEY);
@@ -169,6 +177,7 @@ function loadSettings(): void {
       autoRefreshEnabled = settings.autoRefresh ?? true;
     }
   } catch { /* Use defaults */ }
+  syncNotificationsEnabled();
 }
 
 function saveSettings(): void {
@@ -176,10 +185,32 @@ function saveSettings(): void {
     notifications: notificationsEnabled,
     autoRefresh: autoRefreshEnabled,
   }));
+  syncNotificationsEnabled();
+}
+
+function syncNotificationsEnabled(): void {
+  if (isTauriApp()) invoke("set_notifications_enabled", { enabled: notificationsEnabled }).catch(() => {});
 }
 
 const delay = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
 
+// Commands reject with a structured `{ kind, message, status?, detail? }` error
+function errorMessage(e: unknown): string {
+  if (e instanceof Error) return e.message;
+  if (e && typeof e === 'object' && 'message' in e) return String((e as { message: unknown }).message);
+  return String(e);
+}
+
+// Rejected locally (bad input, refresh cooldown): retrying won't help
+function isValidationError(e: unknown): boolean {
+  return !!e && typeof e === 'object' && (e as { kind?: unknown }).kind === 'validation';
+}
+
+// Activation refused by a usage cap; activate_account with ignoreCap overrides it
+function isCapExceededError(e: unknown): boolean {
+  return !!e && typeof e === 'object' && (e as { kind?: unknown }).kind === 'cap_exceeded';
+}
+
 // Toast notifications
 function showToast(message: string, type: 'success' | 'error' | 'info' = 'success'): void {
   const existing = document.querySelector('.toast');
@@ -203,7 +234,7 @@ function showToast(message: string, type: 'success' | 'error' | 'info' = 'succes
 }
 
 // Confirmation dialog
-function showConfirmDialog(title: string, message: string): Promise<boolean> {
+function showConfirmDialog(title: string, message: string, confirmLabel: string = t('confirm')): Promise<boolean> {
   return new Promise((resolve) => {
     const overlay = document.createElement('div');
     overlay.className = 'dialog-overlay';
@@ -229,7 +260,7 @@ function showConfirmDialog(title: string, message: string): Promise<boolean> {
 
     const confirmBtn = document.createElement('button');
     confirmBtn.className = 'dialog-btn dialog-btn-confirm';
-    confirmBtn.textContent = t('confirm');
+    confirmBtn.textContent = confirmLabel;
 
     actions.appendChild(cancelBtn);
     actions.appendChild(confirmBtn);
@@ -266,6 +297,8 @@ function debounce<T extends (...args: unknown[]) => unknown>(fn: T, ms: number):
 
 // Notifications
 async function checkAndNotify(data: LimitsData): Promise<void> {
+  // The desktop app notifies from Rust (see set_notifications_enabled)
+  if (isTauriApp()) return;
   if (!notificationsEnabled) {
     console.debug("Notifications disabled, skipping");
     return;
@@ -332,6 +365,34 @@ async function checkAndNotify(data: LimitsData): Promise<void> {
 }
 
 
+// Accounts backup
+const ACCOUNTS_FILE_FILTERS = [{ name: 'JSON', extensions: ['json'] }];
+
+async function exportAccounts(): Promise<void> {
+  const path = await save({ defaultPath: 'aicap-accounts.json', filters: ACCOUNTS_FILE_FILTERS });
+  if (!path) return;
+  try {
+    await invoke("export_accounts", { path });
+    showToast(t('accountsExported'), 'success');
+  } catch (e) {
+    console.error("Export accounts error:", e);
+    showToast(errorMessage(e) || t('fetchError'), 'error');
+  }
+}
+
+async function importAccounts(): Promise<void> {
+  const path = await open({ multiple: false, directory: false, filters: ACCOUNTS_FILE_FILTERS });
+  if (!path) return;
+  try {
+    await invoke("import_accounts", { path });
+    showToast(t('accountsImported'), 'success');
+    await refresh();
+  } catch (e) {
+    console.error("Import accounts error:", e);
+    showToast(errorMessage(e) || t('fetchError'), 'error');
+  }
+}
+
 // Autostart
 async function getAutostart(): Promise<boolean> {
   try {
@@ -364,6 +425,7 @@ async function withRetry<T>(fn: () => Promise<T>, retries: number = MAX_RETRIES)
   for (let i = 0; i < retries; i++) {
     try { return await fn(); } catch (e) {
       lastError = e as Error;
+      if (isValidationError(e)) break;
       if (i < retries - 1) await delay(RETRY_DELAY_MS * (i + 1));
     }
   }
@@ -377,7 +439,7 @@ async function checkBackend(): Promise<boolean> {
 // Account management
 async function fetchAccounts(): Promise<void> {
   try {
-    const response = await invoke<{ accounts: Account[] }>("get_accounts", { provider: currentProvider });
+    const response = await invoke<{ accounts: Account[]; count: number }>("get_accounts", { provider: currentProvider });
     accounts = response.accounts || [];
   } catch (e) {
     console.error("Fetch accounts error:", e);
@@ -433,7 +495,18 @@ async function switchAccount(accountId: string): Promise<void> {
     accounts.forEach(a => a.is_active = a.id === accountId);
 
     // Then do the actual switch in background
-    await invoke("activate_account", { accountId });
+    try {
+      await invoke("activate_account", { accountId });
+    } catch (e) {
+      if (!isCapExceededError(e)) throw e;
+      const override = await showConfirmDialog(t('capExceeded'), t('capExceededDesc'), t('activateAnyway'));
+      if (!override) {
+        await fetchAccounts();
+        renderContent();
+        return;
+      }
+      await invoke("activate_account", { accountId, ignoreCap: true });
+    }
 
     // Refresh data in background (don't await to keep UI responsive)
     refresh();
@@ -542,6 +615,10 @@ async function refresh(forceBackendRefresh: boolean = false): Promise<void> {
     const currentLimits = limitsData[currentProvider];
     if (currentLimits?.is_authenticated) await checkAndNotify(currentLimits);
   } catch (e) {
+    if (isValidationError(e)) {
+      showToast(errorMessage(e), 'info');
+      return;
+    }
     console.error("Refresh error:", e);
     // Try cached data on error
     const cached = loadFromCache();
@@ -577,7 +654,7 @@ async function login(): Promise<void> {
         }
       } catch (e) {
         console.error("Login invoke error:", e);
-        const errorMsg = e instanceof Error ? e.message : String(e);
+        const errorMsg = errorMessage(e);
         // Extract detail from error message if present (format: "Error message: detail")
         const detail = errorMsg.includes(':') ? errorMsg.split(':').slice(1).join(':').trim() : errorMsg;
         showToast(detail || t('fetchError'), 'error');
@@ -605,7 +682,7 @@ async function login(): Promise<void> {
         }
       } catch (e) {
         console.error("Browser login error:", e);
-        const errorMsg = e instanceof Error ? e.message : String(e);
+        const errorMsg = errorMessage(e);
         showToast(errorMsg || t('fetchError'), 'error');
         setButtonLoading("loginBtn", false);
         return;
@@ -632,7 +709,7 @@ async function login(): Promise<void> {
     setTimeout(poll, 3000);
   } catch (e) {
     console.error("Login error:", e);
-    const errorMsg = e instanceof Error ? e.message : String(e);
+    const errorMsg = errorMessage(e);
     // Extract detail from error message if present
     const detail = errorMsg.includes(':') ? errorMsg.split(':').slice(1).join(':').trim() : errorMsg;
     showToast(detail || t('fetchError'), 'error');
@@ -745,6 +822,7 @@ function renderSkeleton(): string {
 
 async function renderSettings(content: HTMLElement): Promise<void> {
   const autostartEnabled = await getAutostart();
+  const backendVersion = isTauriApp() ? await invoke<string>("get_backend_version").catch(() => null) : null;
   const currentLocale = getLocale();
   const locales = getAvailableLocales();
 
@@ -788,8 +866,19 @@ async function renderSettings(content: HTMLElement): Promise<void> {
             ${locales.map(l => `<option value="${l.code}" ${l.code === currentLocale ? 'selected' : ''}>${l.name}</option>`).join('')}
           </select>
         </div>
+        ${isTauriApp() ? `
+        <div class="setting-row">
+          <div class="setting-info">
+            <span class="setting-label">${icons.users} ${t('accountsBackup')}</span>
+            <span class="setting-desc">${t('accountsBackupDesc')}</span>
+          </div>
+          <div class="setting-actions">
+            <button class="btn-account-action" id="exportAccountsBtn" title="${t('exportAccounts')}">${icons.download}</button>
+            <button class="btn-account-action" id="importAccountsBtn" title="${t('importAccounts')}">${icons.upload}</button>
+          </div>
+        </div>` : ''}
       </div>
-      <div class="settings-footer"><span class="version">v${appVersion}</span></div>
+      <div class="settings-footer"><span class="version">v${appVersion}${backendVersion ? ` · backend ${escapeHtml(backendVersion)}` : ''}</span></div>
     </div>
   `;
 }
@@ -1273,6 +1362,8 @@ document.addEventListener("click", async (e) => {
   if (btn.id === "refreshBtn") refresh(true);  // Force backend refresh when user clicks refresh button
   if (btn.id === "settingsBtn") { settingsOpen = true; renderContent(); }
   if (btn.id === "closeSettingsBtn") { settingsOpen = false; renderContent(); }
+  if (btn.id === "exportAccountsBtn") exportAccounts();
+  if (btn.id === "importAccountsBtn") importAccounts();
 });
 
 document.addEventListener("change", async (e) => {
@@ -1354,4 +1445,6 @@ document.addEventListener("DOMContentLoaded", () => {
   loadSettings();
   refresh();
   startAutoRefresh();
+  // Tray menu "Settings" item
+  listen("open-settings", () => { settingsOpen = true; renderContent(); }).catch(() => {});
 });